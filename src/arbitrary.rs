@@ -0,0 +1,116 @@
+//! `quickcheck` integration, gated behind the `quickcheck` feature. Provides a
+//! simple (no parallel edges) undirected graph type suitable for use directly
+//! in property tests.
+
+use crate::distributions::binomial::BinomialGraphDistribution;
+use petgraph::visit::EdgeRef;
+use petgraph::{Graph, Undirected};
+use quickcheck::{Arbitrary, Gen};
+use rand::distributions::Distribution;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+/// A simple (no parallel edges) undirected graph, generated via
+/// [`BinomialGraphDistribution`] and suitable for use in `quickcheck`
+/// property tests.
+#[derive(Debug, Clone)]
+pub struct ArbitrarySimpleGraph(pub Graph<usize, (), Undirected>);
+
+/// Draws a value on `[0, 1]` from `g`, by scaling an arbitrary `u64` down by
+/// `u64::MAX`.
+fn uniform_01(g: &mut Gen) -> f64 {
+    (u64::arbitrary(g) as f64) / (u64::MAX as f64)
+}
+
+impl Arbitrary for ArbitrarySimpleGraph {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let nodes = usize::arbitrary(g) % (g.size() + 1);
+
+        // The product of two uniform(0, 1) draws concentrates its mass near
+        // zero, biasing the generated graphs towards sparse.
+        let p = uniform_01(g) * uniform_01(g);
+
+        let distribution =
+            BinomialGraphDistribution::new(nodes, p).expect("p is constructed to lie in [0, 1]");
+
+        // Draw the graph from an RNG seeded off of `g`, rather than `thread_rng()`,
+        // so the whole value is a pure function of `g` and a failing case can be
+        // reproduced by replaying the same `Gen` seed.
+        let mut rng = SmallRng::seed_from_u64(u64::arbitrary(g));
+        ArbitrarySimpleGraph(distribution.sample(&mut rng))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        if self.0.node_count() <= 1 {
+            return Box::new(std::iter::empty());
+        }
+
+        let even = induced_subgraph(&self.0, |weight| weight % 2 == 0);
+        let odd = induced_subgraph(&self.0, |weight| weight % 2 == 1);
+
+        Box::new(
+            vec![ArbitrarySimpleGraph(even), ArbitrarySimpleGraph(odd)].into_iter(),
+        )
+    }
+}
+
+/// Returns the subgraph induced by the nodes whose weight satisfies `predicate`,
+/// renumbering nodes to remain contiguous from zero.
+fn induced_subgraph(
+    graph: &Graph<usize, (), Undirected>,
+    predicate: impl Fn(usize) -> bool,
+) -> Graph<usize, (), Undirected> {
+    let mut sub = Graph::new_undirected();
+    let mut mapping = HashMap::new();
+
+    for node_index in graph.node_indices() {
+        let weight = graph[node_index];
+        if predicate(weight) {
+            mapping.insert(node_index, sub.add_node(sub.node_count()));
+        }
+    }
+
+    for edge in graph.edge_references() {
+        if let (Some(&source), Some(&target)) =
+            (mapping.get(&edge.source()), mapping.get(&edge.target()))
+        {
+            sub.add_edge(source, target, ());
+        }
+    }
+
+    sub
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck::QuickCheck;
+    use rand::thread_rng;
+
+    #[test]
+    fn test_arbitrary_graphs_have_no_parallel_edges() {
+        fn no_parallel_edges(graph: ArbitrarySimpleGraph) -> bool {
+            let mut seen = std::collections::HashSet::new();
+
+            graph.0.edge_references().all(|edge| {
+                let mut pair = [edge.source().index(), edge.target().index()];
+                pair.sort_unstable();
+                seen.insert(pair)
+            })
+        }
+
+        QuickCheck::new().quickcheck(no_parallel_edges as fn(ArbitrarySimpleGraph) -> bool);
+    }
+
+    #[test]
+    fn test_shrink_splits_into_even_and_odd_subgraphs() {
+        let distribution = BinomialGraphDistribution::new(6, 1.0).unwrap();
+        let graph = ArbitrarySimpleGraph(distribution.sample(&mut thread_rng()));
+
+        let shrunk: Vec<_> = graph.shrink().collect();
+        assert_eq!(shrunk.len(), 2);
+        assert_eq!(shrunk[0].0.node_count(), 3);
+        assert_eq!(shrunk[1].0.node_count(), 3);
+    }
+}
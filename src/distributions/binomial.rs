@@ -1,7 +1,9 @@
-use petgraph::{Graph, Undirected};
-use rand::distributions::{Bernoulli, Distribution};
+use crate::{EdgeLike, GraphError, GraphLike};
+use petgraph::{EdgeType, Graph, Undirected};
+use rand::distributions::Distribution;
 use rand::Rng;
 use std::iter::FromIterator;
+use std::marker::PhantomData;
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq)]
@@ -11,56 +13,235 @@ pub enum BinomialGraphError {
 }
 
 #[derive(Debug, Clone)]
-pub struct BinomialGraphDistribution {
+pub struct BinomialGraphDistribution<Ty: EdgeType = Undirected> {
     nodes: usize,
     p: f64,
+    _ty: PhantomData<Ty>,
 }
 
-impl BinomialGraphDistribution {
-    /// Creates a new `BinomialGraphDistribution` with `nodes` nodes, and where up to
-    /// `binomial(nodes, 2)` edges are inserted independently with probability `p`.
+impl<Ty: EdgeType> BinomialGraphDistribution<Ty> {
+    /// Creates a new `BinomialGraphDistribution` with `nodes` nodes, where each of the
+    /// `binomial(nodes, 2)` undirected pairs (or `nodes * (nodes - 1)` ordered pairs,
+    /// if `Ty = Directed`) is inserted independently with probability `p`.
     ///
     /// Will return an error if `p < 0` or `p > 1`.
     ///
     /// # Example
     /// ```rust
     /// use random_graphs::prelude::*;
+    /// use petgraph::Undirected;
     /// use rand::prelude::*;
     ///
-    /// let distribution = BinomialGraphDistribution::new(4, 0.25).unwrap();
+    /// let distribution = BinomialGraphDistribution::<Undirected>::new(4, 0.25).unwrap();
     ///
     /// // Generate a random graph
     /// let graph = distribution.sample(&mut thread_rng());
     /// assert_eq!(graph.node_count(), 4);
     /// ```
+    ///
+    /// A directed graph can be requested by fixing the type parameter:
+    /// ```rust
+    /// use random_graphs::prelude::*;
+    /// use petgraph::Directed;
+    /// use rand::prelude::*;
+    ///
+    /// let distribution = BinomialGraphDistribution::<Directed>::new(4, 0.25).unwrap();
+    /// let graph = distribution.sample(&mut thread_rng());
+    /// assert!(graph.is_directed());
+    /// ```
     pub fn new(nodes: usize, p: f64) -> Result<Self, BinomialGraphError> {
         // Probability must be between 0 and 1.
         if p < 0.0 || p > 1.0 {
             return Err(BinomialGraphError::InvalidProbability(p));
         }
 
-        Ok(Self { nodes, p })
+        Ok(Self {
+            nodes,
+            p,
+            _ty: PhantomData,
+        })
     }
-}
 
-impl Distribution<Graph<usize, (), Undirected>> for BinomialGraphDistribution {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Graph<usize, (), Undirected> {
-        // Expected number of edges is binomial(n, 2) * p
-        let mut graph = Graph::new_undirected();
+    /// Returns the `(source, target)` node indices to connect, following the
+    /// Batagelj-Brandes geometric-skip algorithm. Shared between [`Self::sample`]
+    /// and [`Self::sample_into`] so the two stay in lock-step.
+    fn sample_edges<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
 
-        let nodes = Vec::from_iter((0..self.nodes).map(|index| graph.add_node(index)));
+        // `p = 0` can never produce an edge, and `ln(1 - p)` below would divide by
+        // zero, so special-case the empty graph.
+        if self.p <= 0.0 {
+            return edges;
+        }
+
+        // Likewise `p = 1` always produces every edge, so skip straight to the
+        // complete graph rather than dividing by `ln(1 - p) = ln(0)`.
+        if self.p >= 1.0 {
+            if Ty::is_directed() {
+                for i in 0..self.nodes {
+                    for j in 0..self.nodes {
+                        if i != j {
+                            edges.push((i, j));
+                        }
+                    }
+                }
+            } else {
+                for i in 0..self.nodes {
+                    for j in (i + 1)..self.nodes {
+                        edges.push((i, j));
+                    }
+                }
+            }
+
+            return edges;
+        }
+
+        // Batagelj-Brandes geometric-skip algorithm: rather than drawing a Bernoulli
+        // for every one of the candidate pairs, skip directly to the next accepted
+        // pair. The gap between consecutive accepted pairs follows a geometric
+        // distribution with parameter `p`, so each skip is taken in constant expected
+        // time, giving an overall O(n + m) running time where `m` is the number of
+        // edges produced.
+        let log_not_p = (1.0 - self.p).ln();
+
+        // For vanishingly small `p`, `1.0 - p` rounds to exactly `1.0` in floating
+        // point, making `log_not_p` zero and the division below infinite. Treat
+        // this the same as `p = 0`, since the expected number of edges is already
+        // negligible at that point.
+        if log_not_p == 0.0 {
+            return edges;
+        }
+
+        if Ty::is_directed() {
+            // Candidate pairs are all ordered `(v, w)` with `v != w`. Each row `v`
+            // has only `n - 1` valid targets (every node but `v` itself), so we
+            // flatten as `v * (n - 1) + w` and map `w` back to a target, skipping
+            // over the self-loop slot.
+            let mut v = 0usize;
+            let mut w = -1isize;
+            let row_width = self.nodes.saturating_sub(1) as isize;
+
+            while v < self.nodes {
+                let r: f64 = rng.gen();
+                w += 1 + ((1.0 - r).ln() / log_not_p).floor() as isize;
+
+                while w >= row_width && v < self.nodes {
+                    w -= row_width;
+                    v += 1;
+                }
+
+                if v < self.nodes {
+                    let target = if w < v as isize { w as usize } else { w as usize + 1 };
+                    edges.push((v, target));
+                }
+            }
+        } else {
+            // Candidate pairs are the undirected pairs `(w, v)` with `0 <= w < v < n`.
+            let mut v = 1isize;
+            let mut w = -1isize;
+
+            while v < self.nodes as isize {
+                let r: f64 = rng.gen();
+                w += 1 + ((1.0 - r).ln() / log_not_p).floor() as isize;
 
-        // Unwrap is fine here because we've already verified that 0 <= self.p <= 1.
-        let bernoulli = Bernoulli::new(self.p).unwrap();
+                while w >= v && v < self.nodes as isize {
+                    w -= v;
+                    v += 1;
+                }
 
-        for (index, start_node) in nodes.iter().enumerate() {
-            for end_node in nodes.iter().skip(index + 1) {
-                if bernoulli.sample(rng) {
-                    graph.add_edge(start_node.clone(), end_node.clone(), ());
+                if v < self.nodes as isize {
+                    edges.push((w as usize, v as usize));
                 }
             }
         }
 
+        edges
+    }
+
+    /// Samples this distribution directly into any [`GraphLike`] backend, rather
+    /// than a concrete petgraph [`Graph`]. Each candidate pair is still included
+    /// independently with probability `p`, so the number of edges produced is
+    /// random rather than fixed. This lets downstream users populate a
+    /// [`crate::DumbGraph`], their own adjacency structure, or anything else
+    /// implementing `GraphLike` through `add_node`/`add_edge`, without taking on
+    /// a petgraph dependency.
+    pub fn sample_into<R, E, G>(
+        &self,
+        rng: &mut R,
+        graph: &mut G,
+    ) -> Result<(), GraphError<usize, E>>
+    where
+        R: Rng + ?Sized,
+        E: EdgeLike<usize>,
+        G: GraphLike<usize, E>,
+    {
+        for index in 0..self.nodes {
+            graph.add_node(index);
+        }
+
+        for (source, target) in self.sample_edges(rng) {
+            graph.add_edge(E::new(source, target))?;
+        }
+
+        Ok(())
+    }
+
+    /// Samples this distribution with node and edge payloads drawn from the given
+    /// `node_weights` and `edge_weights` distributions, instead of the default
+    /// `usize` index and `()` payloads. Each payload is drawn from the same `rng`
+    /// as the node or edge it is attached to is created. As with [`Self::sample`],
+    /// each candidate pair is included independently with probability `p`, so the
+    /// resulting edge count is random rather than fixed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use random_graphs::prelude::*;
+    /// use petgraph::Undirected;
+    /// use rand::distributions::Uniform;
+    /// use rand::prelude::*;
+    ///
+    /// let distribution = BinomialGraphDistribution::<Undirected>::new(4, 0.5).unwrap();
+    /// let capacities = Uniform::new(1, 10);
+    ///
+    /// let graph = distribution.sample_weighted(&mut thread_rng(), &Uniform::new(0, 100), &capacities);
+    /// assert_eq!(graph.node_count(), 4);
+    /// ```
+    pub fn sample_weighted<R, N, E, ND, ED>(
+        &self,
+        rng: &mut R,
+        node_weights: &ND,
+        edge_weights: &ED,
+    ) -> Graph<N, E, Ty>
+    where
+        R: Rng + ?Sized,
+        ND: Distribution<N>,
+        ED: Distribution<E>,
+    {
+        let mut graph = Graph::with_capacity(self.nodes, 0);
+
+        let nodes = Vec::from_iter(
+            (0..self.nodes).map(|_| graph.add_node(node_weights.sample(rng))),
+        );
+
+        for (source, target) in self.sample_edges(rng) {
+            let weight = edge_weights.sample(rng);
+            graph.add_edge(nodes[source], nodes[target], weight);
+        }
+
+        graph
+    }
+}
+
+impl<Ty: EdgeType> Distribution<Graph<usize, (), Ty>> for BinomialGraphDistribution<Ty> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Graph<usize, (), Ty> {
+        let mut graph = Graph::with_capacity(self.nodes, 0);
+
+        let nodes = Vec::from_iter((0..self.nodes).map(|index| graph.add_node(index)));
+
+        for (source, target) in self.sample_edges(rng) {
+            graph.add_edge(nodes[source], nodes[target], ());
+        }
+
         graph
     }
 }
@@ -68,22 +249,24 @@ impl Distribution<Graph<usize, (), Undirected>> for BinomialGraphDistribution {
 #[cfg(test)]
 mod test {
     use super::*;
+    use num_integer::binomial;
+    use petgraph::Directed;
     use rand::thread_rng;
 
     #[test]
     fn test_invalid_p_causes_error() {
         // Negative value should cause an error
-        let distribution = BinomialGraphDistribution::new(4, -0.05);
+        let distribution = BinomialGraphDistribution::<Undirected>::new(4, -0.05);
         assert_eq!(distribution.err(), Some(BinomialGraphError::InvalidProbability(-0.05)));
 
         // A couple of p-values that should be fine
         for acceptable_p in &[0.0, 0.05, 0.4, 0.77, 0.33, 0.999, 1.0] {
-            let distribution = BinomialGraphDistribution::new(4, *acceptable_p);
+            let distribution = BinomialGraphDistribution::<Undirected>::new(4, *acceptable_p);
             assert!(distribution.is_ok());
         }
 
         // A value greater than 1 should cause an error
-        let distribution = BinomialGraphDistribution::new(4, 1.01);
+        let distribution = BinomialGraphDistribution::<Undirected>::new(4, 1.01);
         assert_eq!(distribution.err(), Some(BinomialGraphError::InvalidProbability(1.01)));
     }
 
@@ -94,7 +277,7 @@ mod test {
         let nodes = 9;
         let p = 1.0 / 6.0;
 
-        let distribution = BinomialGraphDistribution::new(nodes, p).unwrap();
+        let distribution = BinomialGraphDistribution::<Undirected>::new(nodes, p).unwrap();
         let mut rng = thread_rng();
 
         let iteration_count = 10000;
@@ -111,4 +294,83 @@ mod test {
         let relative_tolerance = (average_number_of_edges - 6.0) / 6.0;
         assert!(relative_tolerance < 0.01);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_boundary_probabilities() {
+        let nodes = 6;
+        let mut rng = thread_rng();
+
+        // p = 0 should never produce an edge.
+        let empty = BinomialGraphDistribution::<Undirected>::new(nodes, 0.0).unwrap();
+        assert_eq!(empty.sample(&mut rng).edge_count(), 0);
+
+        // p = 1 should always produce the complete graph.
+        let complete = BinomialGraphDistribution::<Undirected>::new(nodes, 1.0).unwrap();
+        assert_eq!(complete.sample(&mut rng).edge_count(), binomial(nodes, 2));
+    }
+
+    #[test]
+    fn test_directed_binomial_graph_distribution() {
+        // With 9 nodes there are 72 ordered pairs, twice as many as the undirected
+        // case, so the same `p = 1/6` now yields 12 edges on average.
+        let nodes = 9;
+        let p = 1.0 / 6.0;
+
+        let distribution = BinomialGraphDistribution::<Directed>::new(nodes, p).unwrap();
+        let mut rng = thread_rng();
+
+        let iteration_count = 10000;
+
+        let edge_count: usize = (0..iteration_count)
+            .map(|_| distribution.sample(&mut rng).edge_count())
+            .sum();
+
+        let average_number_of_edges = (edge_count as f64) / (iteration_count as f64);
+        let relative_tolerance = (average_number_of_edges - 12.0) / 12.0;
+        assert!(relative_tolerance < 0.01);
+    }
+
+    #[test]
+    fn test_directed_zero_nodes_does_not_panic() {
+        // A directed distribution over 0 nodes has no candidate pairs at all, so it
+        // should just produce an empty graph rather than underflowing `nodes - 1`.
+        let distribution = BinomialGraphDistribution::<Directed>::new(0, 0.5).unwrap();
+        let mut rng = thread_rng();
+
+        assert_eq!(distribution.sample(&mut rng).edge_count(), 0);
+    }
+
+    #[test]
+    fn test_sample_into_dumb_graph() {
+        use crate::{DumbEdge, DumbGraph};
+
+        let nodes = 6;
+        let distribution = BinomialGraphDistribution::<Undirected>::new(nodes, 1.0).unwrap();
+        let mut rng = thread_rng();
+
+        let mut graph = DumbGraph::new();
+        distribution.sample_into::<_, DumbEdge, _>(&mut rng, &mut graph).unwrap();
+
+        assert_eq!(graph.node_iter().count(), nodes);
+        assert_eq!(graph.edge_iter().count(), binomial(nodes, 2));
+    }
+
+    #[test]
+    fn test_sample_weighted() {
+        use rand::distributions::Uniform;
+
+        let nodes = 6;
+        let distribution = BinomialGraphDistribution::<Undirected>::new(nodes, 1.0).unwrap();
+        let mut rng = thread_rng();
+
+        let node_weights = Uniform::new(0, 100);
+        let edge_weights = Uniform::new(1, 10);
+
+        let graph = distribution.sample_weighted(&mut rng, &node_weights, &edge_weights);
+
+        assert_eq!(graph.node_count(), nodes);
+        assert_eq!(graph.edge_count(), binomial(nodes, 2));
+        assert!(graph.node_weights().all(|&weight| weight < 100));
+        assert!(graph.edge_weights().all(|&weight| (1..10).contains(&weight)));
+    }
+}
@@ -1,10 +1,12 @@
+use crate::{EdgeLike, GraphError, GraphLike};
 use itertools::Itertools;
 use num_integer::binomial;
-use petgraph::{Graph, Undirected};
+use petgraph::{EdgeType, Graph, Undirected};
 use rand::distributions::Distribution;
 use rand::seq::IteratorRandom;
 use rand::Rng;
 use std::iter::FromIterator;
+use std::marker::PhantomData;
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq)]
@@ -14,54 +16,177 @@ pub enum UniformGraphError {
 }
 
 #[derive(Debug, Clone)]
-pub struct UniformGraphDistribution {
+pub struct UniformGraphDistribution<Ty: EdgeType = Undirected> {
     nodes: usize,
     edges: usize,
+    _ty: PhantomData<Ty>,
 }
 
-impl UniformGraphDistribution {
+impl<Ty: EdgeType> UniformGraphDistribution<Ty> {
     /// Creates a new `UniformGraphDistribution` with `nodes` nodes, and `edges` edges.
     ///
-    /// Will return an error if `edges > binomial(nodes, 2)`.
+    /// Will return an error if `edges > binomial(nodes, 2)` (or `edges > nodes * (nodes - 1)`,
+    /// if `Ty = Directed`).
     ///
     /// # Example
     /// ```rust
     /// use random_graphs::prelude::*;
+    /// use petgraph::Undirected;
     /// use rand::prelude::*;
     ///
-    /// let distribution = UniformGraphDistribution::new(4, 2).unwrap();
+    /// let distribution = UniformGraphDistribution::<Undirected>::new(4, 2).unwrap();
     ///
     /// // Generate a random graph
     /// let graph = distribution.sample(&mut thread_rng());
     /// assert_eq!(graph.node_count(), 4);
     /// assert_eq!(graph.edge_count(), 2);
     /// ```
+    ///
+    /// A directed graph can be requested by fixing the type parameter:
+    /// ```rust
+    /// use random_graphs::prelude::*;
+    /// use petgraph::Directed;
+    /// use rand::prelude::*;
+    ///
+    /// let distribution = UniformGraphDistribution::<Directed>::new(4, 6).unwrap();
+    /// let graph = distribution.sample(&mut thread_rng());
+    /// assert!(graph.is_directed());
+    /// assert_eq!(graph.edge_count(), 6);
+    /// ```
     pub fn new(nodes: usize, edges: usize) -> Result<Self, UniformGraphError> {
-        // Cannot have more than C(N, 2) edges in a graph on N edges.
-        if edges > binomial(nodes, 2) {
+        // Cannot have more than C(N, 2) edges in an undirected graph on N nodes, or
+        // more than N * (N - 1) ordered pairs in a directed graph on N nodes.
+        let max_edges = if Ty::is_directed() {
+            nodes * nodes.saturating_sub(1)
+        } else {
+            binomial(nodes, 2)
+        };
+
+        if edges > max_edges {
             return Err(UniformGraphError::TooManyEdges);
         }
 
-        Ok(Self { nodes, edges })
+        Ok(Self {
+            nodes,
+            edges,
+            _ty: PhantomData,
+        })
+    }
+
+    /// Returns the `(source, target)` node indices to connect. Shared between
+    /// [`Self::sample`] and [`Self::sample_into`] so the two stay in lock-step.
+    fn sample_edges<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<(usize, usize)> {
+        let indices: Vec<usize> = (0..self.nodes).collect();
+
+        if Ty::is_directed() {
+            // Candidates are every ordered pair, since `(a, b)` and `(b, a)` are
+            // distinct directed edges.
+            indices
+                .iter()
+                .cartesian_product(indices.iter())
+                // Don't want to have self-loops, so filter out any (node, node) pairs
+                .filter(|(node, other_node)| node != other_node)
+                .map(|(&a, &b)| (a, b))
+                .choose_multiple(rng, self.edges)
+        } else {
+            // Candidates are the unordered pairs `(a, b)` with `a < b`, so that an
+            // undirected edge can't be chosen twice under two different orderings.
+            indices
+                .iter()
+                .enumerate()
+                .flat_map(|(index, &node)| {
+                    indices[index + 1..]
+                        .iter()
+                        .map(move |&other_node| (node, other_node))
+                })
+                .choose_multiple(rng, self.edges)
+        }
+    }
+
+    /// Samples this distribution directly into any [`GraphLike`] backend, rather
+    /// than a concrete petgraph [`Graph`]. Exactly `edges` edges are still chosen
+    /// uniformly at random, so the populated graph always ends up with exactly
+    /// as many edges as this distribution was constructed with. This lets
+    /// downstream users populate a [`crate::DumbGraph`], their own adjacency
+    /// structure, or anything else implementing `GraphLike` through
+    /// `add_node`/`add_edge`, without taking on a petgraph dependency.
+    pub fn sample_into<R, E, G>(
+        &self,
+        rng: &mut R,
+        graph: &mut G,
+    ) -> Result<(), GraphError<usize, E>>
+    where
+        R: Rng + ?Sized,
+        E: EdgeLike<usize>,
+        G: GraphLike<usize, E>,
+    {
+        for index in 0..self.nodes {
+            graph.add_node(index);
+        }
+
+        for (source, target) in self.sample_edges(rng) {
+            graph.add_edge(E::new(source, target))?;
+        }
+
+        Ok(())
+    }
+
+    /// Samples this distribution with node and edge payloads drawn from the given
+    /// `node_weights` and `edge_weights` distributions, instead of the default
+    /// `usize` index and `()` payloads. Each payload is drawn from the same `rng`
+    /// as the node or edge it is attached to is created. As with [`Self::sample`],
+    /// exactly `edges` edges are chosen uniformly at random, so the resulting
+    /// edge count is fixed rather than random.
+    ///
+    /// # Example
+    /// ```rust
+    /// use random_graphs::prelude::*;
+    /// use petgraph::Undirected;
+    /// use rand::distributions::Uniform;
+    /// use rand::prelude::*;
+    ///
+    /// let distribution = UniformGraphDistribution::<Undirected>::new(4, 2).unwrap();
+    /// let lengths = Uniform::new(1, 10);
+    ///
+    /// let graph = distribution.sample_weighted(&mut thread_rng(), &Uniform::new(0, 100), &lengths);
+    /// assert_eq!(graph.node_count(), 4);
+    /// assert_eq!(graph.edge_count(), 2);
+    /// ```
+    pub fn sample_weighted<R, N, E, ND, ED>(
+        &self,
+        rng: &mut R,
+        node_weights: &ND,
+        edge_weights: &ED,
+    ) -> Graph<N, E, Ty>
+    where
+        R: Rng + ?Sized,
+        ND: Distribution<N>,
+        ED: Distribution<E>,
+    {
+        let mut graph = Graph::with_capacity(self.nodes, self.edges);
+
+        let nodes = Vec::from_iter(
+            (0..self.nodes).map(|_| graph.add_node(node_weights.sample(rng))),
+        );
+
+        for (source, target) in self.sample_edges(rng) {
+            let weight = edge_weights.sample(rng);
+            graph.add_edge(nodes[source], nodes[target], weight);
+        }
+
+        graph
     }
 }
 
-impl Distribution<Graph<usize, (), Undirected>> for UniformGraphDistribution {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Graph<usize, (), Undirected> {
+impl<Ty: EdgeType> Distribution<Graph<usize, (), Ty>> for UniformGraphDistribution<Ty> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Graph<usize, (), Ty> {
         let mut graph = Graph::with_capacity(self.nodes, self.edges);
 
         // Add all of our nodes to the graph
         let nodes = Vec::from_iter((0..self.nodes).map(|i| graph.add_node(i)));
 
-        let chosen_edges = nodes
-            .iter()
-            .cartesian_product(nodes.iter())
-            // Don't want to have self-loops, so filter out any (node, node) pairs
-            .filter(|(node, other_node)| node != other_node)
-            .choose_multiple(rng, self.edges);
-
-        for (edge_start, edge_end) in chosen_edges {
-            graph.add_edge(*edge_start, *edge_end, ());
+        for (source, target) in self.sample_edges(rng) {
+            graph.add_edge(nodes[source], nodes[target], ());
         }
 
         graph
@@ -72,15 +197,26 @@ impl Distribution<Graph<usize, (), Undirected>> for UniformGraphDistribution {
 mod test {
     use super::*;
     use petgraph::prelude::EdgeRef;
+    use petgraph::Directed;
     use rand::thread_rng;
 
     #[test]
     fn test_invalid_edge_count_causes_error() {
         // In an undirected graph on 4 nodes, there are at most 6 edges (count them, I dare you!)
-        let distribution = UniformGraphDistribution::new(4, 6);
+        let distribution = UniformGraphDistribution::<Undirected>::new(4, 6);
+        assert!(distribution.is_ok());
+
+        let distribution = UniformGraphDistribution::<Undirected>::new(4, 7);
+        assert_eq!(distribution.err(), Some(UniformGraphError::TooManyEdges));
+    }
+
+    #[test]
+    fn test_directed_invalid_edge_count_causes_error() {
+        // In a directed graph on 4 nodes, there are at most 12 ordered pairs.
+        let distribution = UniformGraphDistribution::<Directed>::new(4, 12);
         assert!(distribution.is_ok());
 
-        let distribution = UniformGraphDistribution::new(4, 7);
+        let distribution = UniformGraphDistribution::<Directed>::new(4, 13);
         assert_eq!(distribution.err(), Some(UniformGraphError::TooManyEdges));
     }
 
@@ -89,9 +225,11 @@ mod test {
         let nodes = 4;
         let edges = 2;
 
-        let distribution = UniformGraphDistribution::new(nodes, edges).unwrap();
+        let distribution = UniformGraphDistribution::<Undirected>::new(nodes, edges).unwrap();
         let mut rng = thread_rng();
 
+        // Since the graph is undirected, bucket by the unordered pair `(lo, hi)`
+        // rather than by `(source, target)` directly.
         let mut edge_buckets = vec![vec![0; nodes]; nodes];
 
         for _ in 0..10000 {
@@ -106,39 +244,22 @@ mod test {
                 // Graph has no self loops
                 assert_ne!(src_index, tgt_index);
 
-                edge_buckets[src_index][tgt_index] += 1;
+                let (lo, hi) = (src_index.min(tgt_index), src_index.max(tgt_index));
+                edge_buckets[lo][hi] += 1;
             }
         }
 
-        let minimum_bucket_size = edge_buckets
+        let minimum_bucket_size = *edge_buckets
             .iter()
             .enumerate()
-            .map(|(index, inner_bucket)| {
-                inner_bucket
-                    .iter()
-                    .enumerate()
-                    .filter(|(inner_index, _)| *inner_index != index)
-                    .min()
-                    .unwrap()
-                    .clone()
-            })
-            .map(|(_, inner_min)| *inner_min)
+            .flat_map(|(lo, inner_bucket)| inner_bucket.iter().skip(lo + 1))
             .min()
             .unwrap();
 
-        let maximum_bucket_size = edge_buckets
+        let maximum_bucket_size = *edge_buckets
             .iter()
             .enumerate()
-            .map(|(index, inner_bucket)| {
-                inner_bucket
-                    .iter()
-                    .enumerate()
-                    .filter(|(inner_index, _)| *inner_index != index)
-                    .max()
-                    .unwrap()
-                    .clone()
-            })
-            .map(|(_, inner_max)| *inner_max)
+            .flat_map(|(lo, inner_bucket)| inner_bucket.iter().skip(lo + 1))
             .max()
             .unwrap();
 
@@ -149,4 +270,56 @@ mod test {
             ((maximum_bucket_size - minimum_bucket_size) as f32) / (minimum_bucket_size as f32);
         assert!(relative_delta < 0.10);
     }
+
+    #[test]
+    fn test_directed_uniform_graph_distribution() {
+        let nodes = 4;
+        let edges = 6;
+
+        let distribution = UniformGraphDistribution::<Directed>::new(nodes, edges).unwrap();
+        let mut rng = thread_rng();
+
+        let graph = distribution.sample(&mut rng);
+        assert!(graph.is_directed());
+        assert_eq!(graph.node_count(), nodes);
+        assert_eq!(graph.edge_count(), edges);
+    }
+
+    #[test]
+    fn test_sample_into_dumb_graph() {
+        use crate::{DumbEdge, DumbGraph};
+
+        let nodes = 4;
+        let edges = 6;
+
+        let distribution = UniformGraphDistribution::<Undirected>::new(nodes, edges).unwrap();
+        let mut rng = thread_rng();
+
+        let mut graph = DumbGraph::new();
+        distribution.sample_into::<_, DumbEdge, _>(&mut rng, &mut graph).unwrap();
+
+        assert_eq!(graph.node_iter().count(), nodes);
+        assert_eq!(graph.edge_iter().count(), edges);
+    }
+
+    #[test]
+    fn test_sample_weighted() {
+        use rand::distributions::Uniform;
+
+        let nodes = 4;
+        let edges = 6;
+
+        let distribution = UniformGraphDistribution::<Undirected>::new(nodes, edges).unwrap();
+        let mut rng = thread_rng();
+
+        let node_weights = Uniform::new(0, 100);
+        let edge_weights = Uniform::new(1, 10);
+
+        let graph = distribution.sample_weighted(&mut rng, &node_weights, &edge_weights);
+
+        assert_eq!(graph.node_count(), nodes);
+        assert_eq!(graph.edge_count(), edges);
+        assert!(graph.node_weights().all(|&weight| weight < 100));
+        assert!(graph.edge_weights().all(|&weight| (1..10).contains(&weight)));
+    }
 }
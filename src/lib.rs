@@ -2,6 +2,21 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use thiserror::Error;
 
+pub mod distributions;
+pub mod standard;
+
+#[cfg(feature = "quickcheck")]
+pub mod arbitrary;
+
+pub mod prelude {
+    //! Re-exports of the types most commonly needed to generate random graphs.
+
+    pub use crate::distributions::binomial::{BinomialGraphDistribution, BinomialGraphError};
+    pub use crate::distributions::uniform::{UniformGraphDistribution, UniformGraphError};
+    pub use crate::standard::{binary_tree, complete, cycle, path, star};
+    pub use crate::{DumbEdge, DumbGraph, EdgeLike, GraphError, GraphLike};
+}
+
 #[derive(Error, Debug, Clone)]
 pub enum GraphError<I: Debug, E: EdgeLike<I>> {
     #[error("node `{0:?}` was not found in graph")]
@@ -11,6 +26,9 @@ pub enum GraphError<I: Debug, E: EdgeLike<I>> {
 }
 
 pub trait EdgeLike<I: Debug>: Debug {
+    /// Creates a new edge between `source` and `target`, with no associated key.
+    fn new(source: I, target: I) -> Self;
+
     fn source(&self) -> &I;
     fn target(&self) -> &I;
     fn key(&self) -> Option<usize>;
@@ -62,6 +80,10 @@ impl DumbEdge {
 }
 
 impl EdgeLike<usize> for DumbEdge {
+    fn new(source: usize, target: usize) -> DumbEdge {
+        DumbEdge::new(source, target)
+    }
+
     fn source(&self) -> &usize {
         &self.source
     }
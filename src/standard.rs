@@ -0,0 +1,159 @@
+//! Deterministic, non-random graph generators for canonical structures. These
+//! are useful as benchmarking fixtures and for seeding random models, and sit
+//! alongside the random generators in [`crate::distributions`].
+
+use num_integer::binomial;
+use petgraph::graph::NodeIndex;
+use petgraph::{Graph, Undirected};
+use std::iter::FromIterator;
+
+/// Returns the complete graph on `n` nodes, i.e. every pair of nodes is joined
+/// by an edge.
+pub fn complete(n: usize) -> Graph<usize, (), Undirected> {
+    let mut graph = Graph::with_capacity(n, binomial(n, 2));
+    let nodes = Vec::from_iter((0..n).map(|index| graph.add_node(index)));
+
+    for (index, &start_node) in nodes.iter().enumerate() {
+        for &end_node in &nodes[index + 1..] {
+            graph.add_edge(start_node, end_node, ());
+        }
+    }
+
+    graph
+}
+
+/// Returns the cycle graph on `n` nodes, i.e. nodes `0, 1, ..., n - 1` joined
+/// in a ring. Graphs of fewer than 3 nodes have no ring to close, so `cycle(0)`
+/// is empty, `cycle(1)` is a single node, and `cycle(2)` is a single edge.
+pub fn cycle(n: usize) -> Graph<usize, (), Undirected> {
+    let mut graph = Graph::with_capacity(n, n);
+    let nodes = Vec::from_iter((0..n).map(|index| graph.add_node(index)));
+
+    if n >= 3 {
+        for index in 0..n {
+            graph.add_edge(nodes[index], nodes[(index + 1) % n], ());
+        }
+    } else if n == 2 {
+        graph.add_edge(nodes[0], nodes[1], ());
+    }
+
+    graph
+}
+
+/// Returns the path graph on `n` nodes, i.e. nodes `0, 1, ..., n - 1` joined in
+/// a line.
+pub fn path(n: usize) -> Graph<usize, (), Undirected> {
+    let mut graph = Graph::with_capacity(n, n.saturating_sub(1));
+    let nodes = Vec::from_iter((0..n).map(|index| graph.add_node(index)));
+
+    for index in 0..n.saturating_sub(1) {
+        graph.add_edge(nodes[index], nodes[index + 1], ());
+    }
+
+    graph
+}
+
+/// Returns the star graph on `n` nodes: node `0` joined to each of the other
+/// `n - 1` nodes.
+pub fn star(n: usize) -> Graph<usize, (), Undirected> {
+    let mut graph = Graph::with_capacity(n, n.saturating_sub(1));
+    let nodes = Vec::from_iter((0..n).map(|index| graph.add_node(index)));
+
+    for &leaf in nodes.iter().skip(1) {
+        graph.add_edge(nodes[0], leaf, ());
+    }
+
+    graph
+}
+
+/// Returns a complete binary tree of the given `depth`, together with the
+/// index of its root node, by adding a root and recursively adding up to two
+/// children per node down to `depth`.
+///
+/// A negative `depth` yields an empty graph with no root. A `depth` of zero
+/// yields a single-node graph consisting of just the root.
+pub fn binary_tree(depth: isize) -> (Graph<usize, (), Undirected>, Option<NodeIndex>) {
+    let mut graph = Graph::new_undirected();
+
+    if depth < 0 {
+        return (graph, None);
+    }
+
+    let root = graph.add_node(0);
+    add_binary_tree_children(&mut graph, root, depth);
+
+    (graph, Some(root))
+}
+
+fn add_binary_tree_children(graph: &mut Graph<usize, (), Undirected>, parent: NodeIndex, depth: isize) {
+    if depth <= 0 {
+        return;
+    }
+
+    for _ in 0..2 {
+        let child = graph.add_node(graph.node_count());
+        graph.add_edge(parent, child, ());
+        add_binary_tree_children(graph, child, depth - 1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_complete() {
+        let graph = complete(5);
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), binomial(5, 2));
+    }
+
+    #[test]
+    fn test_cycle() {
+        assert_eq!(cycle(0).edge_count(), 0);
+        assert_eq!(cycle(1).edge_count(), 0);
+        assert_eq!(cycle(2).edge_count(), 1);
+
+        let graph = cycle(5);
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 5);
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(path(0).edge_count(), 0);
+        assert_eq!(path(1).edge_count(), 0);
+
+        let graph = path(5);
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 4);
+    }
+
+    #[test]
+    fn test_star() {
+        assert_eq!(star(0).edge_count(), 0);
+        assert_eq!(star(1).edge_count(), 0);
+
+        let graph = star(5);
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 4);
+    }
+
+    #[test]
+    fn test_binary_tree() {
+        let (graph, root) = binary_tree(-1);
+        assert_eq!(graph.node_count(), 0);
+        assert!(root.is_none());
+
+        let (graph, root) = binary_tree(0);
+        assert_eq!(graph.node_count(), 1);
+        assert!(root.is_some());
+        assert_eq!(graph.edge_count(), 0);
+
+        // A depth of 2 gives a root, two children, and four grandchildren.
+        let (graph, root) = binary_tree(2);
+        assert_eq!(graph.node_count(), 7);
+        assert_eq!(graph.edge_count(), 6);
+        assert_eq!(root, Some(NodeIndex::new(0)));
+    }
+}